@@ -0,0 +1,140 @@
+// In the name of Allah
+
+use crate::{Duration, HijriDate, HijriError};
+use chrono::{NaiveTime, Timelike, Utc};
+use std::ops::{Add, Sub};
+
+/// A [`HijriDate`] paired with a time-of-day, for scheduling/timestamping use
+/// cases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HijriDateTime {
+    pub date: HijriDate,
+    pub time: NaiveTime,
+}
+
+impl HijriDateTime {
+    /// get data from hijri date and an hour/minute/second time, using the
+    /// Umm al-Qura lookup table.
+    pub fn from_hijri_hms(
+        year: usize,
+        month: usize,
+        day: usize,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Result<Self, HijriError> {
+        let date = HijriDate::from_hijri(year, month, day)?;
+        let time = NaiveTime::from_hms_opt(hour, min, sec).ok_or(HijriError::ParseError)?;
+
+        Ok(Self { date, time })
+    }
+
+    /// get data from today's date and the current time.
+    pub fn now() -> Self {
+        Self {
+            date: HijriDate::today(),
+            time: Utc::now().time(),
+        }
+    }
+
+    /// Returns a representation of HijriDateTime defined by the given formatter.
+    ///
+    /// Understands every specifier [`HijriDate::format`] does, plus:
+    ///
+    /// ```text
+    ///     %H              hour (00-23)
+    ///     %Min            minute (00-59)
+    ///     %S              second (00-59)
+    /// ```
+    pub fn format(&self, f: &str) -> String {
+        let d = &self.date;
+        let mut out = String::with_capacity(f.len());
+        let mut chars = f.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('%') => out.push('%'),
+                Some('H') => out.push_str(&format!("{:02}", self.time.hour())),
+                Some('S') => out.push_str(&format!("{:02}", self.time.second())),
+                Some('M') if matches(&mut chars, "in") => {
+                    out.push_str(&format!("{:02}", self.time.minute()))
+                }
+                Some('Y') => out.push_str(&d.year.to_string()),
+                Some('m') => out.push_str(&d.month.to_string()),
+                Some('d') => out.push_str(&d.day.to_string()),
+                Some('D') => out.push_str(&d.day_name),
+                Some('M') => out.push_str(&d.month_name),
+                Some('E') => out.push_str(&d.month_name_translit),
+                Some('l') => out.push_str(&d.month_len.to_string()),
+                Some('g') => match chars.next() {
+                    Some('Y') => out.push_str(&d.year_gr.to_string()),
+                    Some('m') => out.push_str(&d.month_gr.to_string()),
+                    Some('d') => out.push_str(&d.day_gr.to_string()),
+                    Some('D') => out.push_str(&d.day_name_en),
+                    Some('M') => out.push_str(&d.month_name_en),
+                    Some(other) => {
+                        out.push('%');
+                        out.push('g');
+                        out.push(other);
+                    }
+                    None => out.push_str("%g"),
+                },
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                }
+                None => out.push('%'),
+            }
+        }
+
+        out
+    }
+}
+
+/// Consumes `rest` from `chars` if it comes next, leaving `chars` untouched otherwise.
+fn matches(chars: &mut std::iter::Peekable<std::str::Chars>, rest: &str) -> bool {
+    let mut lookahead = chars.clone();
+    if rest.chars().all(|expected| lookahead.next() == Some(expected)) {
+        *chars = lookahead;
+        true
+    } else {
+        false
+    }
+}
+
+impl Add<Duration> for HijriDateTime {
+    type Output = HijriDateTime;
+
+    fn add(self, other: Duration) -> HijriDateTime {
+        let total = self.time.num_seconds_from_midnight() as i64 + other.num_seconds();
+        let day_carry = total.div_euclid(86_400);
+        let seconds = total.rem_euclid(86_400);
+
+        let date = self.date + Duration::days(day_carry);
+        let time = NaiveTime::from_num_seconds_from_midnight_opt(seconds as u32, 0)
+            .expect("seconds is always in 0..86_400 after rem_euclid");
+
+        HijriDateTime { date, time }
+    }
+}
+
+impl Sub<Duration> for HijriDateTime {
+    type Output = HijriDateTime;
+
+    fn sub(self, other: Duration) -> HijriDateTime {
+        self + (-other)
+    }
+}
+
+impl Sub<HijriDateTime> for HijriDateTime {
+    type Output = Duration;
+
+    fn sub(self, other: HijriDateTime) -> Duration {
+        (self.date - other.date) + (self.time - other.time)
+    }
+}
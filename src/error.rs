@@ -0,0 +1,21 @@
+// In the name of Allah
+
+use thiserror::Error;
+
+/// Errors returned while validating or constructing a [`HijriDate`](crate::HijriDate).
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HijriError {
+    /// The month is not in the `1..=12` range.
+    #[error("invalid month: {0}, expected a value between 1 and 12")]
+    InvalidMonth(usize),
+    /// The day is not in the valid range for the calendar being used.
+    #[error("invalid day: {0}")]
+    InvalidDay(usize),
+    /// The year falls outside the range the conversion backend can handle.
+    #[error("year out of range: expected a value between {min} and {max}")]
+    YearOutOfRange { min: usize, max: usize },
+    /// The gregorian date does not exist (e.g. 31 February), or the given
+    /// hour/minute/second is not a valid time of day.
+    #[error("failed to parse date")]
+    ParseError,
+}
@@ -14,6 +14,12 @@
 //! maximum handled gregorian year = 2076
 //! ```
 //!
+//! This is the range of the bundled Umm al-Qura lookup table
+//! (`HijriCalendar::UmmAlQura`, the default). The `Tabular` and `Civil`
+//! reckonings compute dates arithmetically instead, so they are not bound by
+//! this range; dates before 1 Muharram 1 AH are not supported, as the hijri
+//! year is unsigned.
+//!
 //! ## Usage
 //!
 //! *convert to gregorian*
@@ -22,7 +28,7 @@
 //! extern crate hijri_date;
 //! use hijri_date::HijriDate;
 //!
-//! let hd = HijriDate::from_hijri(1439,11,19);
+//! let hd = HijriDate::from_hijri(1439,11,19).unwrap();
 //! assert_eq!((2018,8,1),(hd.year_gr,hd.month_gr,hd.day_gr));
 //! ```
 //!
@@ -32,7 +38,7 @@
 //! extern crate hijri_date;
 //! use hijri_date::HijriDate;
 //!
-//! let hd = HijriDate::from_gr(2000,07,31);
+//! let hd = HijriDate::from_gr(2000,07,31).unwrap();
 //! assert_eq!((1421,4,29),(hd.year,hd.month,hd.day));
 //! ```
 //!
@@ -42,18 +48,38 @@
 //! extern crate hijri_date;
 //! use hijri_date::HijriDate;
 //!
-//! let hd = HijriDate::from_hijri(1439,11,18);
+//! let hd = HijriDate::from_hijri(1439,11,18).unwrap();
 //! println!("{}",hd.format("%Y %M %D"));
 //! ```
 //!
+//! *transliterated (Latin-script) hijri month name*
+//!
+//! ```rust
+//! extern crate hijri_date;
+//! use hijri_date::HijriDate;
+//!
+//! let hd = HijriDate::from_hijri(1439,9,1).unwrap();
+//! assert_eq!("Ramadan", hd.format("%E"));
+//! ```
+//!
+//! *literal `l` and `%` characters survive formatting*
+//!
+//! ```rust
+//! extern crate hijri_date;
+//! use hijri_date::HijriDate;
+//!
+//! let hd = HijriDate::from_hijri(1439,11,18).unwrap();
+//! assert_eq!("real life, 100%", hd.format("real life, 100%%"));
+//! ```
+//!
 //! *compare dates*
 //!
 //! ```rust
 //! extern crate hijri_date;
 //! use hijri_date::HijriDate;
 //!
-//! let hd_1 = HijriDate::from_hijri(1500, 12, 30);
-//! let hd_2 = HijriDate::from_hijri(1356, 1, 1);
+//! let hd_1 = HijriDate::from_hijri(1500, 12, 30).unwrap();
+//! let hd_2 = HijriDate::from_hijri(1356, 1, 1).unwrap();
 //! assert!(hd_1 > hd_2);
 //! ```
 //!
@@ -63,8 +89,8 @@
 //! extern crate hijri_date;
 //! use hijri_date::{Duration,HijriDate};
 //!
-//! let hd_1 = HijriDate::from_hijri(1420, 06, 15);
-//! let hd_2 = HijriDate::from_hijri(1420, 05, 29);
+//! let hd_1 = HijriDate::from_hijri(1420, 06, 15).unwrap();
+//! let hd_2 = HijriDate::from_hijri(1420, 05, 29).unwrap();
 //! assert_eq!(hd_1 - Duration::days(16), hd_2);
 //! ```
 //!
@@ -74,16 +100,56 @@
 //! extern crate hijri_date;
 //! use hijri_date::{Duration,HijriDate};
 //!
-//! let hd_1 = HijriDate::from_hijri(1356, 06, 15);
-//! let hd_2 = HijriDate::from_hijri(1356, 06, 7);
+//! let hd_1 = HijriDate::from_hijri(1356, 06, 15).unwrap();
+//! let hd_2 = HijriDate::from_hijri(1356, 06, 7).unwrap();
 //! assert_eq!(hd_1-hd_2,Duration::days(8));
 //! ```
 //!
+//! *convert a year outside the Umm al-Qura table's range*
+//!
+//! ```rust
+//! extern crate hijri_date;
+//! use hijri_date::{HijriCalendar,HijriDate};
+//!
+//! let hd = HijriDate::from_gr_with(2200, 1, 1, HijriCalendar::Tabular).unwrap();
+//! assert_eq!((1626,11,14), (hd.year,hd.month,hd.day));
+//! ```
+//!
+//! *use `HijriDate` as a set/map key*
+//!
+//! ```rust
+//! extern crate hijri_date;
+//! use std::collections::BTreeSet;
+//! use hijri_date::HijriDate;
+//!
+//! let mut set = BTreeSet::new();
+//! set.insert(HijriDate::from_hijri(1420, 06, 15).unwrap());
+//! set.insert(HijriDate::from_hijri(1420, 06, 15).unwrap().clone());
+//! assert_eq!(1, set.len());
+//! ```
+//!
+//! *carry a time-of-day alongside a hijri date*
+//!
+//! ```rust
+//! extern crate hijri_date;
+//! use hijri_date::{Duration,HijriDateTime};
+//!
+//! let hdt = HijriDateTime::from_hijri_hms(1439, 11, 18, 23, 30, 0).unwrap();
+//! let hdt = hdt + Duration::minutes(45);
+//! assert_eq!("19 00:15:00", hdt.format("%d %H:%Min:%S"));
+//! ```
+//!
 
+mod datetime;
+mod error;
+mod tabular;
 mod umalqura;
 use umalqura::*;
 mod umalqura_array;
 
+pub use datetime::HijriDateTime;
+pub use error::HijriError;
+
 use arabic_reshaper::arabic_reshape_l;
 
 pub use chrono::Duration;
@@ -93,6 +159,7 @@ use once_cell::sync::Lazy;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Sub};
 
 static MONTH_DICT: Lazy<HashMap<usize, String>> = Lazy::new(|| {
@@ -114,6 +181,27 @@ static MONTH_DICT: Lazy<HashMap<usize, String>> = Lazy::new(|| {
     .map(|(n, s)| (*n, arabic_reshape_l(s)))
     .collect()
 });
+/// Transliterated (Latin-script) hijri month names, for locales that can't
+/// display Arabic glyphs.
+static MONTH_DICT_TRANSLIT: Lazy<HashMap<usize, String>> = Lazy::new(|| {
+    [
+        (1, "Moharram"),
+        (2, "Safar"),
+        (3, "Rabie-I"),
+        (4, "Rabie-II"),
+        (5, "Jumada-I"),
+        (6, "Jumada-II"),
+        (7, "Rajab"),
+        (8, "Shaban"),
+        (9, "Ramadan"),
+        (10, "Shawwal"),
+        (11, "Zol-Qeda"),
+        (12, "Zol-Hijja"),
+    ]
+    .iter()
+    .map(|(n, s)| (*n, s.to_string()))
+    .collect()
+});
 static DAY_DICT: Lazy<HashMap<String, String>> = Lazy::new(|| {
     [
         ("Saturday", "السبت"),
@@ -129,11 +217,47 @@ static DAY_DICT: Lazy<HashMap<String, String>> = Lazy::new(|| {
     .collect()
 });
 
+/// Selects which Islamic calendar reckoning is used to convert between hijri
+/// and gregorian dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HijriCalendar {
+    /// The bundled Umm al-Qura lookup table.
+    ///
+    /// Limited to hijri 1356-1500 / gregorian 1938-2076.
+    UmmAlQura,
+    /// Arithmetic tabular Islamic calendar, computed via the Julian Day Number
+    /// using the standard civil epoch.
+    ///
+    /// Not limited to the Umm al-Qura table's year range.
+    Tabular,
+    /// Arithmetic tabular Islamic calendar with a configurable epoch (the
+    /// Julian Day Number of 1 Muharram, year 1 AH), for variants such as the
+    /// Fatimid or other civil reckonings that share the same 30-year,
+    /// 11-leap-year cycle but start from a different epoch.
+    ///
+    /// Only the epoch is configurable; the leap-year cycle itself is the
+    /// fixed standard 11-in-30-year pattern.
+    Civil { epoch: i64 },
+}
+
+impl HijriCalendar {
+    fn epoch(self) -> i64 {
+        match self {
+            HijriCalendar::Civil { epoch } => epoch,
+            _ => tabular::DEFAULT_EPOCH,
+        }
+    }
+}
+
 ///Main structure.
 ///  - Contains numeric value of hijri and gregorian dates plus hijri month and day names.
 ///  - Hijri names dosent have suffix, example (day,month,year,..)
 ///  - Gregorian names are denoted with `gr` or `en` suffix.
-#[derive(Debug, PartialEq)]
+///
+/// `PartialEq`, `Eq`, `Hash` and `Ord` are all defined in terms of
+/// [`to_julian_day`](HijriDate::to_julian_day), a single canonical day count,
+/// rather than the formatted string fields.
+#[derive(Debug, Clone)]
 pub struct HijriDate {
     //hijri
     pub day: usize,
@@ -142,6 +266,8 @@ pub struct HijriDate {
     pub year: usize,
     pub day_name: String,
     pub month_name: String,
+    /// Transliterated (Latin-script) hijri month name, e.g. "Ramadan".
+    pub month_name_translit: String,
 
     //gregorian
     pub day_gr: usize,
@@ -149,6 +275,9 @@ pub struct HijriDate {
     pub year_gr: usize,
     pub day_name_en: String,
     pub month_name_en: String,
+    /// Calendar reckoning this date was built under; kept so `format` and
+    /// arithmetic (`+`, `-`) stay consistent with it.
+    pub calendar: HijriCalendar,
     // needed to ease trait impl(add,sub,partialeq..)
     date_gr: Date<Utc>,
 }
@@ -166,7 +295,7 @@ impl Add<Duration> for HijriDate {
     type Output = HijriDate;
 
     fn add(self, other: Duration) -> HijriDate {
-        HijriDate::chrno_to_hijri(self.date_gr + other)
+        HijriDate::chrno_to_hijri(self.date_gr + other, self.calendar)
     }
 }
 
@@ -174,7 +303,7 @@ impl Sub<Duration> for HijriDate {
     type Output = HijriDate;
 
     fn sub(self, other: Duration) -> HijriDate {
-        HijriDate::chrno_to_hijri(self.date_gr - other)
+        HijriDate::chrno_to_hijri(self.date_gr - other, self.calendar)
     }
 }
 
@@ -186,38 +315,90 @@ impl Sub<HijriDate> for HijriDate {
     }
 }
 
+impl PartialEq for HijriDate {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_julian_day() == other.to_julian_day()
+    }
+}
+
+impl Eq for HijriDate {}
+
 impl PartialOrd for HijriDate {
-    //use chrono to implement cmp
     fn partial_cmp(&self, other: &HijriDate) -> Option<Ordering> {
-        Some(self.date_gr.cmp(&other.date_gr))
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HijriDate {
+    fn cmp(&self, other: &HijriDate) -> Ordering {
+        self.to_julian_day().cmp(&other.to_julian_day())
+    }
+}
+
+impl Hash for HijriDate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_julian_day().hash(state);
     }
 }
 
 impl HijriDate {
-    /// get data from hijri date
-    pub fn from_hijri(year: usize, month: usize, day: usize) -> Self {
-        valid_hijri_date(year, month, day);
+    /// get data from hijri date, using the Umm al-Qura lookup table.
+    pub fn from_hijri(year: usize, month: usize, day: usize) -> Result<Self, HijriError> {
+        Self::from_hijri_with(year, month, day, HijriCalendar::UmmAlQura)
+    }
+
+    /// get data from hijri date, using the given `HijriCalendar` reckoning.
+    ///
+    /// `HijriCalendar::Tabular`/`Civil` are not bound by the Umm al-Qura
+    /// table's year range.
+    pub fn from_hijri_with(
+        year: usize,
+        month: usize,
+        day: usize,
+        calendar: HijriCalendar,
+    ) -> Result<Self, HijriError> {
+        let (year_gr, month_gr, day_gr) = match calendar {
+            HijriCalendar::UmmAlQura => {
+                valid_hijri_date(year, month, day)?;
+                hijri_to_gregorian(year, month, day)
+            }
+            HijriCalendar::Tabular | HijriCalendar::Civil { .. } => {
+                if !(1..=12).contains(&month) {
+                    return Err(HijriError::InvalidMonth(month));
+                }
+                if !(1..=30).contains(&day) {
+                    return Err(HijriError::InvalidDay(day));
+                }
+                let jd = tabular::hijri_to_julian(year as i64, month as i64, day as i64, calendar.epoch());
+                let (year_gr, month_gr, day_gr) = tabular::julian_to_gregorian(jd);
+                (year_gr as usize, month_gr as usize, day_gr as usize)
+            }
+        };
 
         let month_name = MONTH_DICT[&month].clone();
-        let (year_gr, month_gr, day_gr) = hijri_to_gregorian(year, month, day);
+        let month_name_translit = MONTH_DICT_TRANSLIT[&month].clone();
         let date_gr = format!("{}-{}-{}", year_gr, month_gr, day_gr);
-        let date_gr = if let Ok(date_gr) = NaiveDate::parse_from_str(&date_gr, "%Y-%m-%d") {
-            Date::<Utc>::from_utc(date_gr, Utc)
-        } else {
-            panic!("Wrong gegorean date foramt")
-        };
+        let date_gr = NaiveDate::parse_from_str(&date_gr, "%Y-%m-%d")
+            .map(|date_gr| Date::<Utc>::from_utc(date_gr, Utc))
+            .map_err(|_| HijriError::ParseError)?;
         let day_name_en = date_gr.format("%A").to_string();
         let day_name = DAY_DICT[&day_name_en].clone();
         let month_name_en = date_gr.format("%B").to_string();
-        let (_, _, _, month_len) = gegorean_to_hijri(year_gr, month_gr, day_gr);
+        let month_len = match calendar {
+            HijriCalendar::UmmAlQura => gegorean_to_hijri(year_gr, month_gr, day_gr).3,
+            HijriCalendar::Tabular | HijriCalendar::Civil { .. } => {
+                tabular::month_len(year as i64, month as i64, calendar.epoch()) as usize
+            }
+        };
 
-        Self {
+        Ok(Self {
             day,
             month,
             month_len,
             year,
             day_name,
             month_name,
+            month_name_translit,
 
             //gregorian
             day_gr,
@@ -225,28 +406,58 @@ impl HijriDate {
             year_gr,
             day_name_en,
             month_name_en,
+            calendar,
             date_gr,
-        }
+        })
+    }
+    /// get data from gregorian date, using the Umm al-Qura lookup table.
+    pub fn from_gr(year_gr: usize, month_gr: usize, day_gr: usize) -> Result<Self, HijriError> {
+        Self::from_gr_with(year_gr, month_gr, day_gr, HijriCalendar::UmmAlQura)
     }
-    /// get data from gregorian date.
-    pub fn from_gr(year_gr: usize, month_gr: usize, day_gr: usize) -> Self {
-        valid_greorian_date(year_gr, month_gr, day_gr);
 
-        let date_gr = format!("{}-{}-{}", year_gr, month_gr, day_gr);
-        let date_gr = if let Ok(date_gr) = NaiveDate::parse_from_str(&date_gr, "%Y-%m-%d") {
-            Date::<Utc>::from_utc(date_gr, Utc)
+    /// get data from gregorian date, using the given `HijriCalendar` reckoning.
+    ///
+    /// `HijriCalendar::Tabular`/`Civil` are not bound by the Umm al-Qura
+    /// table's year range.
+    pub fn from_gr_with(
+        year_gr: usize,
+        month_gr: usize,
+        day_gr: usize,
+        calendar: HijriCalendar,
+    ) -> Result<Self, HijriError> {
+        if calendar == HijriCalendar::UmmAlQura {
+            valid_greorian_date(year_gr, month_gr, day_gr)?;
         } else {
-            panic!("Wrong gegorean date foramt")
-        };
+            if !(1..=12).contains(&month_gr) {
+                return Err(HijriError::InvalidMonth(month_gr));
+            }
+            if !(1..=31).contains(&day_gr) {
+                return Err(HijriError::InvalidDay(day_gr));
+            }
+        }
 
-        let (year, month, day, month_len) = gegorean_to_hijri(year_gr, month_gr, day_gr);
+        let date_gr = format!("{}-{}-{}", year_gr, month_gr, day_gr);
+        let date_gr = NaiveDate::parse_from_str(&date_gr, "%Y-%m-%d")
+            .map(|date_gr| Date::<Utc>::from_utc(date_gr, Utc))
+            .map_err(|_| HijriError::ParseError)?;
+
+        let (year, month, day, month_len) = match calendar {
+            HijriCalendar::UmmAlQura => gegorean_to_hijri(year_gr, month_gr, day_gr),
+            HijriCalendar::Tabular | HijriCalendar::Civil { .. } => {
+                let jd = tabular::gregorian_to_julian(year_gr as i64, month_gr as i64, day_gr as i64);
+                let (year, month, day) = tabular::julian_to_hijri(jd, calendar.epoch());
+                let month_len = tabular::month_len(year, month, calendar.epoch());
+                (year as usize, month as usize, day as usize, month_len as usize)
+            }
+        };
         let month_name = MONTH_DICT[&month].clone();
+        let month_name_translit = MONTH_DICT_TRANSLIT[&month].clone();
 
         let day_name_en = date_gr.format("%A").to_string();
         let day_name = DAY_DICT[&day_name_en].clone();
         let month_name_en = date_gr.format("%B").to_string();
 
-        Self {
+        Ok(Self {
             //hijri
             day,
             month,
@@ -254,6 +465,7 @@ impl HijriDate {
             year,
             day_name,
             month_name,
+            month_name_translit,
 
             //gregorian
             day_gr,
@@ -261,24 +473,41 @@ impl HijriDate {
             year_gr,
             day_name_en,
             month_name_en,
+            calendar,
             date_gr,
-        }
+        })
     }
     /// get data from today's date.
     pub fn today() -> Self {
         let today = Utc::today();
 
-        Self::chrno_to_hijri(today)
+        Self::chrno_to_hijri(today, HijriCalendar::UmmAlQura)
     }
 
     //helper method
-    fn chrno_to_hijri(date: Date<Utc>) -> Self {
+    fn chrno_to_hijri(date: Date<Utc>, calendar: HijriCalendar) -> Self {
         let (year_gr, month_gr, day_gr): (usize, usize, usize) = (
             date.format("%Y").to_string().parse().unwrap(),
             date.format("%m").to_string().parse().unwrap(),
             date.format("%d").to_string().parse().unwrap(),
         );
-        HijriDate::from_gr(year_gr, month_gr, day_gr)
+        HijriDate::from_gr_with(year_gr, month_gr, day_gr, calendar)
+            .expect("date is outside the range supported by the selected calendar")
+    }
+
+    /// Returns the Julian Day Number of this date.
+    ///
+    /// This is the single canonical integer `PartialEq`, `Eq`, `Hash` and
+    /// `Ord` are defined in terms of.
+    pub fn to_julian_day(&self) -> i64 {
+        tabular::gregorian_to_julian(self.year_gr as i64, self.month_gr as i64, self.day_gr as i64)
+    }
+
+    /// Builds a `HijriDate` from a Julian Day Number, using the Umm al-Qura
+    /// lookup table.
+    pub fn from_julian_day(jd: i64) -> Result<Self, HijriError> {
+        let (year_gr, month_gr, day_gr) = tabular::julian_to_gregorian(jd);
+        HijriDate::from_gr(year_gr as usize, month_gr as usize, day_gr as usize)
     }
 
     /// Returns a representation of HijriDate defined by the given formatter
@@ -291,6 +520,7 @@ impl HijriDate {
     ///     %d              hijri_day
     ///     %D              hijri_day_name
     ///     %M              hijri_month_name
+    ///     %E              hijri_month_name, transliterated to Latin script
     ///     %l              hijri_month_len
     ///
     ///        gregorian
@@ -300,50 +530,85 @@ impl HijriDate {
     ///     %gd             gregorian_day
     ///     %gD             gregorian_day_name
     ///     %gM             gregorian_month_name
+    ///
+    ///     %%              a literal percent sign
     /// ```
+    ///
+    /// Unrecognized specifiers are left untouched, literal percent sign aside.
     pub fn format(&self, f: &str) -> String {
-        f.replace("%Y", &self.year.to_string())
-            .replace("%m", &self.month.to_string())
-            .replace("%d", &self.day.to_string())
-            .replace("%D", &self.day_name)
-            .replace("%M", &self.month_name)
-            .replace("l", &self.month_len.to_string())
-            .replace("%gY", &self.year_gr.to_string())
-            .replace("%gm", &self.month_gr.to_string())
-            .replace("%gd", &self.day_gr.to_string())
-            .replace("%gD", &self.day_name_en)
-            .replace("%gM", &self.month_name_en)
+        let mut out = String::with_capacity(f.len());
+        let mut chars = f.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('%') => out.push('%'),
+                Some('Y') => out.push_str(&self.year.to_string()),
+                Some('m') => out.push_str(&self.month.to_string()),
+                Some('d') => out.push_str(&self.day.to_string()),
+                Some('D') => out.push_str(&self.day_name),
+                Some('M') => out.push_str(&self.month_name),
+                Some('E') => out.push_str(&self.month_name_translit),
+                Some('l') => out.push_str(&self.month_len.to_string()),
+                Some('g') => match chars.next() {
+                    Some('Y') => out.push_str(&self.year_gr.to_string()),
+                    Some('m') => out.push_str(&self.month_gr.to_string()),
+                    Some('d') => out.push_str(&self.day_gr.to_string()),
+                    Some('D') => out.push_str(&self.day_name_en),
+                    Some('M') => out.push_str(&self.month_name_en),
+                    Some(other) => {
+                        out.push('%');
+                        out.push('g');
+                        out.push(other);
+                    }
+                    None => out.push_str("%g"),
+                },
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                }
+                None => out.push('%'),
+            }
+        }
+
+        out
     }
 }
 
-fn valid_hijri_date(year: usize, month: usize, day: usize) {
-    if month > 12 {
-        panic!("enter a valid month, Err m = {}", month);
+fn valid_hijri_date(year: usize, month: usize, day: usize) -> Result<(), HijriError> {
+    if !(1..=12).contains(&month) {
+        return Err(HijriError::InvalidMonth(month));
     }
-    if day > 30 {
-        panic!("enter a valid day, Err d = {}", day);
+    if !(1..=30).contains(&day) {
+        return Err(HijriError::InvalidDay(day));
     }
     //hack to cmp to max min ; should be replaced by a proper way
-    if year < 1356 {
-        panic!("minumum handled hijri year is 1356");
-    }
-    if year > 1500 {
-        panic!("maximum handled hijri year is 1500");
+    if !(1356..=1500).contains(&year) {
+        return Err(HijriError::YearOutOfRange {
+            min: 1356,
+            max: 1500,
+        });
     }
+    Ok(())
 }
 
-fn valid_greorian_date(year_gr: usize, month_gr: usize, day_gr: usize) {
+fn valid_greorian_date(year_gr: usize, month_gr: usize, day_gr: usize) -> Result<(), HijriError> {
     if month_gr > 12 {
-        panic!("enter a valid month, Err m = {}", month_gr);
+        return Err(HijriError::InvalidMonth(month_gr));
     }
     if day_gr > 31 {
-        panic!("enter a valid day, Err d = {}", day_gr);
+        return Err(HijriError::InvalidDay(day_gr));
     }
     //hack to cmp to max min ; should be replaced by a proper way
-    if year_gr < 1938 {
-        panic!("minumum handled gregorian year is 1938");
-    }
-    if year_gr > 2076 {
-        panic!("maximum handled gregorian year is 2076");
+    if !(1938..=2076).contains(&year_gr) {
+        return Err(HijriError::YearOutOfRange {
+            min: 1938,
+            max: 2076,
+        });
     }
+    Ok(())
 }
@@ -0,0 +1,116 @@
+// In the name of Allah
+
+//! Arithmetic (tabular) conversion backend.
+//!
+//! Unlike the bundled Umm al-Qura lookup table, this backend computes the
+//! conversion via the Julian Day Number, so it is not limited to the
+//! hijri 1356-1500 / gregorian 1938-2076 window the table covers.
+
+/// Converts a gregorian date to its Julian Day Number.
+pub fn gregorian_to_julian(year: i64, month: i64, day: i64) -> i64 {
+    let (mut y, mut m) = (year, month);
+    if m <= 2 {
+        m += 12;
+        y -= 1;
+    }
+    let a = y / 100;
+    let b = 2 - a + a / 4;
+
+    (365.25 * (y + 4716) as f64).floor() as i64
+        + (30.6001 * (m + 1) as f64).floor() as i64
+        + day
+        + b
+        - 1524
+}
+
+/// Julian Day Number of 1 Muharram, year 1 AH under the standard civil/tabular epoch.
+pub const DEFAULT_EPOCH: i64 = 1948440;
+
+/// Converts a tabular hijri date to its Julian Day Number, for the calendar
+/// whose epoch (JD of 1 Muharram, year 1 AH) is `epoch`.
+pub fn hijri_to_julian(year: i64, month: i64, day: i64, epoch: i64) -> i64 {
+    day + (29.5 * (month - 1) as f64).ceil() as i64 + (year - 1) * 354 + (3 + 11 * year) / 30
+        + epoch
+        - 1
+}
+
+/// Converts a Julian Day Number to a tabular hijri `(year, month, day)`, for the
+/// calendar whose epoch (JD of 1 Muharram, year 1 AH) is `epoch`.
+///
+/// This is the exact inverse of [`hijri_to_julian`]: it locates the year and
+/// month whose first day's Julian Day Number brackets `jd`, then reads the
+/// day off as the remaining offset, so the two functions always round-trip.
+pub fn julian_to_hijri(jd: i64, epoch: i64) -> (i64, i64, i64) {
+    let mut year = (30 * (jd - epoch) + 10646) / 10631;
+    while hijri_to_julian(year + 1, 1, 1, epoch) <= jd {
+        year += 1;
+    }
+    while hijri_to_julian(year, 1, 1, epoch) > jd {
+        year -= 1;
+    }
+
+    let mut month = (jd - hijri_to_julian(year, 1, 1, epoch)) * 12 / 355 + 1;
+    month = month.clamp(1, 12);
+    while month < 12 && hijri_to_julian(year, month + 1, 1, epoch) <= jd {
+        month += 1;
+    }
+    while hijri_to_julian(year, month, 1, epoch) > jd {
+        month -= 1;
+    }
+
+    let day = jd - hijri_to_julian(year, month, 1, epoch) + 1;
+    (year, month, day)
+}
+
+/// Converts a Julian Day Number to a gregorian `(year, month, day)`.
+pub fn julian_to_gregorian(jd: i64) -> (i64, i64, i64) {
+    let a = jd + 32044;
+    let b = (4 * a + 3) / 146097;
+    let c = a - (146097 * b) / 4;
+    let d = (4 * c + 3) / 1461;
+    let e = c - (1461 * d) / 4;
+    let m = (5 * e + 2) / 153;
+
+    let day = e - (153 * m + 2) / 5 + 1;
+    let month = m + 3 - 12 * (m / 10);
+    let year = 100 * b + d - 4800 + m / 10;
+
+    (year, month, day)
+}
+
+/// Number of days in `month` of tabular hijri `year`, for the calendar whose
+/// epoch (JD of 1 Muharram, year 1 AH) is `epoch`.
+///
+/// Computed as the gap between the Julian Day Numbers of this month's first
+/// day and the next month's first day, so it always agrees with the month
+/// boundaries [`hijri_to_julian`]/[`julian_to_hijri`] actually produce.
+pub fn month_len(year: i64, month: i64, epoch: i64) -> i64 {
+    let next = if month == 12 {
+        hijri_to_julian(year + 1, 1, 1, epoch)
+    } else {
+        hijri_to_julian(year, month + 1, 1, epoch)
+    };
+
+    next - hijri_to_julian(year, month, 1, epoch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hijri_to_julian_is_the_exact_inverse_of_julian_to_hijri() {
+        for year in 1..1500 {
+            for month in 1..=12 {
+                for day in 1..=month_len(year, month, DEFAULT_EPOCH) {
+                    let jd = hijri_to_julian(year, month, day, DEFAULT_EPOCH);
+                    assert_eq!(
+                        julian_to_hijri(jd, DEFAULT_EPOCH),
+                        (year, month, day),
+                        "round trip failed for {year}-{month}-{day}"
+                    );
+                }
+            }
+        }
+    }
+}